@@ -1,5 +1,8 @@
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, MouseButton,
+        MouseEvent, MouseEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -8,65 +11,491 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    widgets::{
+        Axis, Block, Borders, Cell, Chart, Clear, Dataset, GraphType, ListState, Paragraph, Row,
+        Table, TableState, Wrap,
+    },
     Frame, Terminal,
 };
+use serde::Deserialize;
+use std::collections::VecDeque;
 use std::io::{self, stdout};
+use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
+#[cfg(unix)]
+use sysinfo::Signal;
 use sysinfo::{Pid, System};
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time};
 
 #[derive(PartialEq)]
 enum AppMode {
     ProcessSelect,
     TimerInput,
     TimerRunning,
+    KillConfirm,
+    ScheduleList,
+}
+
+/// 예약된 종료 작업. 하나의 프로세스를 지정한 시각에 종료한다
+struct ScheduledKill {
+    pid: Pid,
+    name: String,
+    fire_at: OffsetDateTime,
+    signal: KillSignal,
+}
+
+/// 타이머 입력 파싱 결과 — 상대 시간(초) 또는 절대 시각
+enum TimerSpec {
+    Relative(u64),
+    Absolute(OffsetDateTime),
+}
+
+#[cfg(unix)]
+#[derive(Clone, Copy, PartialEq)]
+enum KillSignal {
+    Term,
+    Int,
+    Kill,
+}
+
+#[cfg(unix)]
+impl KillSignal {
+    fn next(self) -> Self {
+        match self {
+            KillSignal::Term => KillSignal::Int,
+            KillSignal::Int => KillSignal::Kill,
+            KillSignal::Kill => KillSignal::Term,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            KillSignal::Term => "SIGTERM (정상 종료)",
+            KillSignal::Int => "SIGINT (인터럽트)",
+            KillSignal::Kill => "SIGKILL (강제 종료)",
+        }
+    }
+
+    fn to_sysinfo(self) -> Signal {
+        match self {
+            KillSignal::Term => Signal::Term,
+            KillSignal::Int => Signal::Int,
+            KillSignal::Kill => Signal::Kill,
+        }
+    }
+}
+
+#[cfg(unix)]
+impl Default for KillSignal {
+    fn default() -> Self {
+        KillSignal::Term
+    }
+}
+
+#[cfg(windows)]
+#[derive(Clone, Copy, PartialEq)]
+enum KillSignal {
+    Normal,
+    Force,
+}
+
+#[cfg(windows)]
+impl KillSignal {
+    fn next(self) -> Self {
+        match self {
+            KillSignal::Normal => KillSignal::Force,
+            KillSignal::Force => KillSignal::Normal,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            KillSignal::Normal => "일반 종료",
+            KillSignal::Force => "강제 종료 (/F)",
+        }
+    }
+}
+
+#[cfg(windows)]
+impl Default for KillSignal {
+    fn default() -> Self {
+        KillSignal::Normal
+    }
+}
+
+/// SIGTERM 전송 후 SIGKILL로 승급하기까지 대기하는 유예 시간(초)
+const ESCALATION_GRACE_SECS: u64 = 5;
+
+/// 타이머 실행 중 유지하는 리소스 사용량 샘플 개수
+const USAGE_HISTORY_CAP: usize = 120;
+
+#[derive(Clone, Copy)]
+struct UsageSample {
+    elapsed_secs: f64,
+    cpu_usage: f64,
+    memory: u64,
+}
+
+enum KillEscalation {
+    None,
+    AwaitingGraceful {
+        pid: Pid,
+        name: String,
+        sent_at: Instant,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum SortMode {
+    Name,
+    Pid,
+    Cpu,
+    Memory,
+}
+
+impl SortMode {
+    fn next(self) -> Self {
+        match self {
+            SortMode::Name => SortMode::Pid,
+            SortMode::Pid => SortMode::Cpu,
+            SortMode::Cpu => SortMode::Memory,
+            SortMode::Memory => SortMode::Name,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            SortMode::Name => "이름",
+            SortMode::Pid => "PID",
+            SortMode::Cpu => "CPU%",
+            SortMode::Memory => "메모리",
+        }
+    }
+}
+
+/// 설정 파일에 기록되는 기본값. `--config`로 경로를 지정하지 않으면
+/// 사용자 설정 디렉터리 아래 `rust_test/config.toml`을 사용한다.
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+struct Config {
+    require_kill_confirmation: bool,
+    default_signal: String,
+    refresh_interval_ms: u64,
+    default_sort: String,
+    preset_timer_durations: Vec<u64>,
+    basic_mode: bool,
+    escalate_on_kill: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            require_kill_confirmation: true,
+            default_signal: default_signal_label().to_string(),
+            refresh_interval_ms: 100,
+            default_sort: String::from("name"),
+            preset_timer_durations: vec![60, 300, 600],
+            basic_mode: false,
+            escalate_on_kill: false,
+        }
+    }
+}
+
+#[cfg(unix)]
+fn default_signal_label() -> &'static str {
+    "term"
+}
+
+#[cfg(windows)]
+fn default_signal_label() -> &'static str {
+    "normal"
+}
+
+#[cfg(unix)]
+fn kill_signal_from_str(s: &str) -> KillSignal {
+    match s {
+        "int" => KillSignal::Int,
+        "kill" => KillSignal::Kill,
+        _ => KillSignal::Term,
+    }
+}
+
+#[cfg(windows)]
+fn kill_signal_from_str(s: &str) -> KillSignal {
+    match s {
+        "force" => KillSignal::Force,
+        _ => KillSignal::Normal,
+    }
+}
+
+fn sort_mode_from_str(s: &str) -> SortMode {
+    match s {
+        "pid" => SortMode::Pid,
+        "cpu" => SortMode::Cpu,
+        "memory" => SortMode::Memory,
+        _ => SortMode::Name,
+    }
+}
+
+/// 주석이 달린 기본 설정 파일 내용. 최초 실행 시 설정 파일이 없으면 그대로 기록한다.
+const DEFAULT_CONFIG_TOML: &str = r#"# 프로세스 종료 타이머 설정 파일
+# 이 파일을 지우면 다음 실행 시 기본값으로 다시 생성됩니다.
+
+# 종료 전 확인 대화상자를 표시할지 여부
+require_kill_confirmation = true
+
+# 기본 종료 시그널 (유닉스: "term" | "int" | "kill", 윈도우: "normal" | "force")
+default_signal = "term"
+
+# 프로세스 목록을 갱신하는 주기 (밀리초)
+refresh_interval_ms = 100
+
+# 기본 정렬 기준 ("name" | "pid" | "cpu" | "memory")
+default_sort = "name"
+
+# 타이머 입력 화면에서 F1, F3~F9 키로 바로 채울 수 있는 사전 설정 시간(초)
+# (F2는 간략 모드 전환에 쓰이므로 건너뛴다)
+preset_timer_durations = [60, 300, 600]
+
+# 그래프와 테두리를 생략하는 간략 모드로 시작할지 여부 (F2로 실행 중에도 전환 가능)
+basic_mode = false
+
+# SIGTERM을 먼저 보내고 유예 시간 후에도 살아있으면 SIGKILL로 에스컬레이션할지 여부
+# (종료 확인 대화상자에서 'e' 키로 실행 중에도 전환 가능)
+escalate_on_kill = false
+"#;
+
+impl Config {
+    /// `explicit_path`가 주어지면 그 경로를, 아니면 사용자 설정 디렉터리를 사용한다.
+    fn resolve_path(explicit_path: Option<PathBuf>) -> PathBuf {
+        explicit_path.unwrap_or_else(|| {
+            dirs::config_dir()
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join("rust_test")
+                .join("config.toml")
+        })
+    }
+
+    /// 설정 파일을 읽어온다. 파일이 없으면 주석이 달린 기본값을 써 두고,
+    /// 파싱에 실패하면 경고만 남기고 기본값으로 계속 진행한다.
+    fn load(explicit_path: Option<PathBuf>) -> Config {
+        let path = Self::resolve_path(explicit_path);
+
+        match std::fs::read_to_string(&path) {
+            Ok(content) => toml::from_str(&content).unwrap_or_else(|err| {
+                eprintln!("설정 파일을 읽는 중 오류가 발생하여 기본값을 사용합니다: {err}");
+                Config::default()
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                Self::write_default(&path);
+                Config::default()
+            }
+            Err(err) => {
+                eprintln!("설정 파일을 읽는 중 오류가 발생하여 기본값을 사용합니다: {err}");
+                Config::default()
+            }
+        }
+    }
+
+    fn write_default(path: &Path) {
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        let _ = std::fs::write(path, DEFAULT_CONFIG_TOML);
+    }
+}
+
+#[derive(Clone)]
+struct ProcessInfo {
+    pid: Pid,
+    name: String,
+    cpu_usage: f32,
+    memory: u64,
+    parent_pid: Option<Pid>,
+    run_time: u64,
+    match_score: i64,
+    match_indices: Vec<usize>,
+}
+
+/// 퍼지 서브시퀀스 매칭: `query`의 각 글자가 `candidate`에 순서대로 나타나야 하며,
+/// 하나라도 찾지 못하면 `None`을 반환한다. 점수가 높을수록 더 나은 매치다.
+fn fuzzy_match(query: &str, candidate: &str) -> Option<(i64, Vec<usize>)> {
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+    let mut indices = Vec::new();
+    let mut score: i64 = 0;
+    let mut last_match: Option<usize> = None;
+    let mut search_start = 0usize;
+
+    for qc in query.chars() {
+        let qc_lower = qc.to_ascii_lowercase();
+        let idx = (search_start..candidate_chars.len())
+            .find(|&i| candidate_chars[i].to_ascii_lowercase() == qc_lower)?;
+
+        score += 1; // 기본 점수
+
+        let is_boundary = idx == 0
+            || matches!(candidate_chars[idx - 1], ' ' | '_' | '-')
+            || (candidate_chars[idx - 1].is_lowercase() && candidate_chars[idx].is_uppercase());
+        if is_boundary {
+            score += 3;
+        }
+
+        if let Some(last) = last_match {
+            if idx == last + 1 {
+                score += 2; // 연속 매치 보너스
+            } else {
+                score -= (idx - last - 1) as i64; // 건너뛴 글자 패널티
+            }
+        }
+
+        indices.push(idx);
+        last_match = Some(idx);
+        search_start = idx + 1;
+    }
+
+    Some((score, indices))
+}
+
+/// "14:30" 같은 시:분 형식을 오늘(이미 지났다면 내일)의 절대 시각으로 해석한다.
+/// 사용자가 입력하는 시:분은 로컬 시간대 기준이므로, 로컬 오프셋을 가져올 수
+/// 없으면(컨테이너 등에서 흔함) UTC로 조용히 가정하지 않고 에러를 반환한다.
+fn parse_absolute_time_of_day(input: &str) -> Result<OffsetDateTime, ()> {
+    let (hour_str, minute_str) = input.split_once(':').ok_or(())?;
+    let hour: u8 = hour_str.parse().map_err(|_| ())?;
+    let minute: u8 = minute_str.parse().map_err(|_| ())?;
+
+    let now = OffsetDateTime::now_local().map_err(|_| ())?;
+    let time = Time::from_hms(hour, minute, 0).map_err(|_| ())?;
+    let mut fire_at = PrimitiveDateTime::new(now.date(), time).assume_offset(now.offset());
+    if fire_at <= now {
+        fire_at += time::Duration::days(1);
+    }
+    Ok(fire_at)
+}
+
+/// "2025-06-01 09:00" 같은 날짜+시각 형식을 절대 시각으로 해석한다.
+/// `parse_absolute_time_of_day`와 마찬가지로 로컬 시간대를 사용하며, 로컬
+/// 오프셋을 확인할 수 없으면 에러를 반환한다.
+fn parse_absolute_datetime(date_part: &str, time_part: &str) -> Result<OffsetDateTime, ()> {
+    let mut date_fields = date_part.splitn(3, '-');
+    let year: i32 = date_fields.next().ok_or(())?.parse().map_err(|_| ())?;
+    let month: u8 = date_fields.next().ok_or(())?.parse().map_err(|_| ())?;
+    let day: u8 = date_fields.next().ok_or(())?.parse().map_err(|_| ())?;
+
+    let (hour_str, minute_str) = time_part.split_once(':').ok_or(())?;
+    let hour: u8 = hour_str.parse().map_err(|_| ())?;
+    let minute: u8 = minute_str.parse().map_err(|_| ())?;
+
+    let month = Month::try_from(month).map_err(|_| ())?;
+    let date = Date::from_calendar_date(year, month, day).map_err(|_| ())?;
+    let time = Time::from_hms(hour, minute, 0).map_err(|_| ())?;
+    let local_offset = OffsetDateTime::now_local().map_err(|_| ())?.offset();
+    Ok(PrimitiveDateTime::new(date, time).assume_offset(local_offset))
 }
 
 struct App {
     system: System,
-    processes: Vec<(Pid, String)>,
-    filtered_processes: Vec<(Pid, String)>,
+    processes: Vec<ProcessInfo>,
+    filtered_processes: Vec<ProcessInfo>,
     list_state: ListState,
     search_query: String,
+    search_active: bool,
     selected_pid: Option<Pid>,
     mode: AppMode,
     timer_input: String,
-    timer_seconds: u64,
     timer_start: Option<Instant>,
     status_message: String,
+    sort_mode: SortMode,
+    sort_ascending: bool,
+    require_kill_confirmation: bool,
+    kill_signal: KillSignal,
+    escalation_enabled: bool,
+    kill_escalation: KillEscalation,
+    usage_history: VecDeque<UsageSample>,
+    scheduled_kills: Vec<ScheduledKill>,
+    schedule_list_state: ListState,
+    process_list_area: Rect,
+    process_list_scroll_offset: usize,
+    last_click: Option<(Instant, usize)>,
+    refresh_interval: Duration,
+    preset_timer_durations: Vec<u64>,
+    basic_mode: bool,
 }
 
 impl App {
-    fn new() -> Self {
+    fn new(config: &Config) -> Self {
         let mut app = App {
             system: System::new(),
             processes: Vec::new(),
             filtered_processes: Vec::new(),
             list_state: ListState::default(),
             search_query: String::new(),
+            search_active: false,
             selected_pid: None,
             mode: AppMode::ProcessSelect,
             timer_input: String::new(),
-            timer_seconds: 0,
             timer_start: None,
-            status_message: String::from("프로세스를 선택하세요 (↑↓: 이동, /: 검색, Enter: 선택)"),
+            status_message: String::from(
+                "프로세스를 선택하세요 (↑↓: 이동, /: 검색, s: 정렬, Enter: 선택)",
+            ),
+            sort_mode: sort_mode_from_str(&config.default_sort),
+            sort_ascending: true,
+            require_kill_confirmation: config.require_kill_confirmation,
+            kill_signal: kill_signal_from_str(&config.default_signal),
+            escalation_enabled: config.escalate_on_kill,
+            kill_escalation: KillEscalation::None,
+            usage_history: VecDeque::with_capacity(USAGE_HISTORY_CAP),
+            scheduled_kills: Vec::new(),
+            schedule_list_state: ListState::default(),
+            process_list_area: Rect::default(),
+            process_list_scroll_offset: 0,
+            last_click: None,
+            refresh_interval: Duration::from_millis(config.refresh_interval_ms),
+            preset_timer_durations: config.preset_timer_durations.clone(),
+            basic_mode: config.basic_mode,
         };
         app.refresh_processes();
         app
     }
 
     fn refresh_processes(&mut self) {
+        // CPU 사용률은 이전 refresh와의 델타로 계산된다. System을 tick마다
+        // 한 번만 refresh하고 계속 살려두면(이미 그렇게 하고 있음) 그 델타가
+        // 생기지만, 같은 tick 안에서 두 번 연속 호출하면 간격이 마이크로초
+        // 단위로 줄어들어 cpu_usage()가 항상 0에 가깝게 나온다.
         self.system.refresh_all();
+
+        let selected_pid = self
+            .list_state
+            .selected()
+            .and_then(|i| self.filtered_processes.get(i))
+            .map(|p| p.pid);
+
+        let num_cpus = self.system.cpus().len().max(1) as f32;
         self.processes.clear();
-        
+
         for (pid, process) in self.system.processes() {
-            let name = process.name().to_string();
-            self.processes.push((*pid, name));
+            self.processes.push(ProcessInfo {
+                pid: *pid,
+                name: process.name().to_string(),
+                cpu_usage: process.cpu_usage() / num_cpus,
+                memory: process.memory(),
+                parent_pid: process.parent(),
+                run_time: process.run_time(),
+                match_score: 0,
+                match_indices: Vec::new(),
+            });
         }
-        
-        self.processes.sort_by(|a, b| a.1.cmp(&b.1));
+
         self.filter_processes();
-        
+
+        if let Some(pid) = selected_pid {
+            if let Some(i) = self.filtered_processes.iter().position(|p| p.pid == pid) {
+                self.list_state.select(Some(i));
+                return;
+            }
+        }
+
         if !self.filtered_processes.is_empty() && self.list_state.selected().is_none() {
             self.list_state.select(Some(0));
         }
@@ -75,21 +504,139 @@ impl App {
     fn filter_processes(&mut self) {
         if self.search_query.is_empty() {
             self.filtered_processes = self.processes.clone();
+            for p in &mut self.filtered_processes {
+                p.match_score = 0;
+                p.match_indices.clear();
+            }
         } else {
-            let query = self.search_query.to_lowercase();
             self.filtered_processes = self
                 .processes
                 .iter()
-                .filter(|(_, name)| name.to_lowercase().contains(&query))
-                .cloned()
+                .filter_map(|p| {
+                    fuzzy_match(&self.search_query, &p.name).map(|(score, indices)| {
+                        let mut process = p.clone();
+                        process.match_score = score;
+                        process.match_indices = indices;
+                        process
+                    })
+                })
                 .collect();
         }
-        
+
+        self.sort_processes();
+
         // 선택된 인덱스 조정
         if let Some(selected) = self.list_state.selected() {
             if selected >= self.filtered_processes.len() {
-                self.list_state.select(Some(self.filtered_processes.len().saturating_sub(1)));
+                self.list_state
+                    .select(Some(self.filtered_processes.len().saturating_sub(1)));
+            }
+        }
+    }
+
+    fn sort_processes(&mut self) {
+        // 검색어가 있으면 정렬 모드와 무관하게 매치 점수 순으로 정렬한다
+        if !self.search_query.is_empty() {
+            self.filtered_processes.sort_by(|a, b| {
+                b.match_score
+                    .cmp(&a.match_score)
+                    .then_with(|| a.name.cmp(&b.name))
+            });
+            return;
+        }
+
+        match self.sort_mode {
+            SortMode::Name => self.filtered_processes.sort_by(|a, b| a.name.cmp(&b.name)),
+            SortMode::Pid => self.filtered_processes.sort_by_key(|p| p.pid),
+            SortMode::Cpu => self.filtered_processes.sort_by(|a, b| {
+                a.cpu_usage
+                    .partial_cmp(&b.cpu_usage)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            }),
+            SortMode::Memory => self.filtered_processes.sort_by_key(|p| p.memory),
+        }
+
+        if !self.sort_ascending {
+            self.filtered_processes.reverse();
+        }
+    }
+
+    fn cycle_sort_mode(&mut self) {
+        self.sort_mode = self.sort_mode.next();
+        self.sort_processes();
+        self.status_message = format!(
+            "정렬 기준: {} ({})",
+            self.sort_mode.label(),
+            if self.sort_ascending {
+                "오름차순"
+            } else {
+                "내림차순"
+            }
+        );
+    }
+
+    fn toggle_sort_direction(&mut self) {
+        self.sort_ascending = !self.sort_ascending;
+        self.sort_processes();
+        self.status_message = format!(
+            "정렬 기준: {} ({})",
+            self.sort_mode.label(),
+            if self.sort_ascending {
+                "오름차순"
+            } else {
+                "내림차순"
+            }
+        );
+    }
+
+    fn schedule_list_next(&mut self) {
+        if self.scheduled_kills.is_empty() {
+            return;
+        }
+        let i = match self.schedule_list_state.selected() {
+            Some(i) => {
+                if i >= self.scheduled_kills.len().saturating_sub(1) {
+                    0
+                } else {
+                    i + 1
+                }
             }
+            None => 0,
+        };
+        self.schedule_list_state.select(Some(i));
+    }
+
+    fn schedule_list_previous(&mut self) {
+        if self.scheduled_kills.is_empty() {
+            return;
+        }
+        let i = match self.schedule_list_state.selected() {
+            Some(i) => {
+                if i == 0 {
+                    self.scheduled_kills.len().saturating_sub(1)
+                } else {
+                    i - 1
+                }
+            }
+            None => 0,
+        };
+        self.schedule_list_state.select(Some(i));
+    }
+
+    fn cancel_selected_schedule(&mut self) {
+        let Some(i) = self.schedule_list_state.selected() else {
+            return;
+        };
+        if i >= self.scheduled_kills.len() {
+            return;
+        }
+        let removed = self.scheduled_kills.remove(i);
+        self.status_message = format!("{}의 예약 종료가 취소되었습니다.", removed.name);
+        if self.scheduled_kills.is_empty() {
+            self.schedule_list_state.select(None);
+        } else if i >= self.scheduled_kills.len() {
+            self.schedule_list_state
+                .select(Some(self.scheduled_kills.len() - 1));
         }
     }
 
@@ -121,120 +668,416 @@ impl App {
         self.list_state.select(Some(i));
     }
 
+    /// 마우스 클릭 좌표를 마지막으로 렌더링된 프로세스 목록 영역 기준으로
+    /// `filtered_processes`의 인덱스로 변환한다
+    fn process_list_index_at(&self, column: u16, row: u16) -> Option<usize> {
+        let area = self.process_list_area;
+        if column < area.x
+            || column >= area.x + area.width
+            || row < area.y
+            || row >= area.y + area.height
+        {
+            return None;
+        }
+        let relative_row = (row - area.y) as usize;
+        let index = self.process_list_scroll_offset + relative_row;
+        if index < self.filtered_processes.len() {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
     fn select_process(&mut self) {
         if let Some(selected) = self.list_state.selected() {
-            if let Some((pid, _)) = self.filtered_processes.get(selected) {
-                self.selected_pid = Some(*pid);
+            if let Some(process) = self.filtered_processes.get(selected) {
+                self.selected_pid = Some(process.pid);
                 self.mode = AppMode::TimerInput;
-                self.status_message = format!("타이머 시간을 입력하세요 (분:초 형식, 예: 5:30 또는 300초)");
+                self.status_message =
+                    String::from("종료 시각을 입력하세요 (예: 5:30, 300초, 14:30, 2025-06-01 09:00)");
             }
         }
     }
 
     fn start_timer(&mut self) {
-        if let Ok(seconds) = self.parse_timer_input() {
-            self.timer_seconds = seconds;
-            self.timer_start = Some(Instant::now());
-            self.mode = AppMode::TimerRunning;
-            self.status_message = format!("타이머 실행 중... (Q: 취소)");
-        } else {
-            self.status_message = format!("잘못된 형식입니다. 예: 5:30 또는 300");
+        let Some(pid) = self.selected_pid else {
+            return;
+        };
+
+        match self.parse_timer_input() {
+            Ok(spec) => {
+                let fire_at = match spec {
+                    TimerSpec::Relative(seconds) => {
+                        OffsetDateTime::now_utc() + time::Duration::seconds(seconds as i64)
+                    }
+                    TimerSpec::Absolute(dt) => dt,
+                };
+
+                let name = self.selected_process_name();
+                self.scheduled_kills.push(ScheduledKill {
+                    pid,
+                    name,
+                    fire_at,
+                    signal: self.kill_signal,
+                });
+
+                self.timer_start = Some(Instant::now());
+                self.mode = AppMode::TimerRunning;
+                self.status_message = String::from("타이머 실행 중... (Q: 취소, b: 백그라운드로 보내기)");
+                self.usage_history.clear();
+            }
+            Err(()) => {
+                self.status_message =
+                    String::from("잘못된 형식입니다. 예: 5:30, 300, 14:30(시:분), 2025-06-01 09:00");
+            }
+        }
+    }
+
+    /// 설정 파일의 `preset_timer_durations`를 F1, F3~F9 키로 바로 채운다
+    /// (F2는 간략 모드 전환에 쓰이므로 사전 설정 목록에서 건너뛴다)
+    fn fill_preset_timer(&mut self, function_key: u8) {
+        let index = match function_key {
+            1 => 0,
+            n @ 3..=9 => (n - 2) as usize,
+            _ => return,
+        };
+        if let Some(seconds) = self.preset_timer_durations.get(index).copied() {
+            self.timer_input = seconds.to_string();
+        }
+    }
+
+    fn num_cpus(&self) -> f32 {
+        self.system.cpus().len().max(1) as f32
+    }
+
+    /// 타이머 실행 중 매 루프마다 선택된 프로세스의 CPU/메모리 사용량을 기록한다
+    fn sample_usage(&mut self) {
+        let Some(pid) = self.selected_pid else {
+            return;
+        };
+        let num_cpus = self.num_cpus();
+        self.system.refresh_process(pid);
+        let Some(process) = self.system.process(pid) else {
+            return;
+        };
+        let elapsed_secs = self
+            .timer_start
+            .map(|start| start.elapsed().as_secs_f64())
+            .unwrap_or(0.0);
+        self.usage_history.push_back(UsageSample {
+            elapsed_secs,
+            cpu_usage: (process.cpu_usage() / num_cpus) as f64,
+            memory: process.memory(),
+        });
+        if self.usage_history.len() > USAGE_HISTORY_CAP {
+            self.usage_history.pop_front();
         }
     }
 
-    fn parse_timer_input(&self) -> Result<u64, ()> {
+    /// 타이머 입력을 파싱한다. 지원 형식:
+    /// - "300"          : 상대 시간(초)
+    /// - "5:30"         : 상대 시간(분:초) — 시(분) 부분이 1자리
+    /// - "14:30"        : 절대 시각(오늘 또는, 이미 지났으면 내일의 시:분) — 시 부분이 2자리로 0 패딩됨
+    ///                    (2자리라도 유효한 시:분이 아니면, 예: "45:00", 분:초로 해석)
+    /// - "2025-06-01 09:00" : 절대 날짜+시각
+    fn parse_timer_input(&self) -> Result<TimerSpec, ()> {
         let input = self.timer_input.trim();
-        
-        // 분:초 형식 처리 (예: 5:30)
+
+        if let Some((date_part, time_part)) = input.split_once(' ') {
+            if date_part.contains('-') {
+                return parse_absolute_datetime(date_part, time_part).map(TimerSpec::Absolute);
+            }
+        }
+
         if let Some(colon_pos) = input.find(':') {
+            let hour_part = &input[..colon_pos];
+            let is_time_of_day = hour_part.len() == 2
+                && hour_part.chars().all(|c| c.is_ascii_digit())
+                && hour_part.parse::<u8>().map_or(false, |h| h < 24)
+                && input[colon_pos + 1..].parse::<u8>().map_or(false, |m| m < 60);
+            if is_time_of_day {
+                return parse_absolute_time_of_day(input).map(TimerSpec::Absolute);
+            }
+
             let minutes: u64 = input[..colon_pos].parse().map_err(|_| ())?;
             let seconds: u64 = input[colon_pos + 1..].parse().map_err(|_| ())?;
-            return Ok(minutes * 60 + seconds);
+            return Ok(TimerSpec::Relative(minutes * 60 + seconds));
         }
-        
-        // 초 단위만 입력 (예: 300)
+
         if let Ok(seconds) = input.parse::<u64>() {
-            return Ok(seconds);
+            return Ok(TimerSpec::Relative(seconds));
         }
-        
+
         Err(())
     }
 
     fn get_remaining_time(&self) -> Option<u64> {
-        if let Some(start) = self.timer_start {
-            let elapsed = start.elapsed().as_secs();
-            if elapsed >= self.timer_seconds {
-                return Some(0);
-            }
-            return Some(self.timer_seconds - elapsed);
+        let pid = self.selected_pid?;
+        let entry = self.scheduled_kills.iter().find(|k| k.pid == pid)?;
+        let remaining = (entry.fire_at - OffsetDateTime::now_utc()).whole_seconds();
+        Some(remaining.max(0) as u64)
+    }
+
+    fn cancel_scheduled_for(&mut self, pid: Pid) {
+        self.scheduled_kills.retain(|k| k.pid != pid);
+    }
+
+    /// 실행 중인 타이머를 배경으로 보내고(예약은 그대로 유지) 프로세스 선택 화면으로
+    /// 돌아간다. 이렇게 해야 여러 개의 예약 종료를 동시에 쌓아 둘 수 있다
+    fn detach_timer(&mut self) {
+        let name = self.selected_process_name();
+        self.mode = AppMode::ProcessSelect;
+        self.selected_pid = None;
+        self.timer_start = None;
+        self.status_message = format!(
+            "{}에 대한 타이머가 백그라운드에서 계속 실행됩니다 (l: 예약 목록)",
+            name
+        );
+    }
+
+    fn selected_process_name(&self) -> String {
+        self.selected_pid
+            .and_then(|pid| self.processes.iter().find(|p| p.pid == pid))
+            .map(|p| p.name.clone())
+            .unwrap_or_else(|| "알 수 없음".to_string())
+    }
+
+    /// 타이머가 만료되었을 때 호출: 확인이 필요하면 확인 대화상자로 전환하고,
+    /// 그렇지 않으면 곧바로 종료를 진행한다.
+    fn request_kill(&mut self) {
+        if self.require_kill_confirmation {
+            self.mode = AppMode::KillConfirm;
+            self.status_message = format!(
+                "{}(을)를 종료하시겠습니까? (Enter: 확인, ←→: 시그널 선택, Esc: 취소)",
+                self.selected_process_name()
+            );
+        } else if self.escalation_enabled {
+            self.begin_escalation();
+        } else {
+            self.finish_kill(self.kill_signal);
         }
-        None
     }
 
-    fn kill_process(&mut self) -> bool {
+    fn confirm_kill(&mut self) {
+        if self.escalation_enabled {
+            self.begin_escalation();
+        } else {
+            self.finish_kill(self.kill_signal);
+        }
+    }
+
+    fn cancel_kill(&mut self) {
         if let Some(pid) = self.selected_pid {
-            self.system.refresh_process(pid);
-            if let Some(process) = self.system.process(pid) {
-                #[cfg(windows)]
-                {
-                    // Windows에서는 taskkill 명령어 사용
-                    use std::process::Command;
-                    let pid_u32: u32 = (*pid).into();
-                    let pid_str = pid_u32.to_string();
-                    let output = Command::new("taskkill")
-                        .args(&["/PID", &pid_str, "/F"])
-                        .output();
-                    
-                    if let Ok(result) = output {
-                        return result.status.success();
-                    }
-                    return false;
+            self.cancel_scheduled_for(pid);
+        }
+        self.mode = AppMode::ProcessSelect;
+        self.selected_pid = None;
+        self.timer_start = None;
+        self.status_message = String::from("종료가 취소되었습니다.");
+    }
+
+    fn cycle_kill_signal(&mut self) {
+        self.kill_signal = self.kill_signal.next();
+    }
+
+    /// 종료 확인 대화상자에서 SIGTERM→유예→SIGKILL 에스컬레이션 사용 여부를 전환한다
+    fn toggle_escalation(&mut self) {
+        self.escalation_enabled = !self.escalation_enabled;
+    }
+
+    fn finish_kill(&mut self, signal: KillSignal) {
+        let name = self.selected_process_name();
+        if let Some(pid) = self.selected_pid {
+            if self.kill_pid(pid, signal) {
+                self.status_message = format!("{}(이)가 종료되었습니다.", name);
+            } else {
+                self.status_message = format!("{} 종료 실패", name);
+            }
+            self.cancel_scheduled_for(pid);
+        }
+        self.mode = AppMode::ProcessSelect;
+        self.selected_pid = None;
+        self.timer_start = None;
+        self.refresh_processes();
+    }
+
+    fn begin_escalation(&mut self) {
+        #[cfg(unix)]
+        {
+            let name = self.selected_process_name();
+            if let Some(pid) = self.selected_pid {
+                self.kill_pid(pid, KillSignal::Term);
+                self.status_message = format!(
+                    "{}에 SIGTERM을 보냈습니다. {}초 후에도 살아있으면 SIGKILL을 보냅니다.",
+                    name, ESCALATION_GRACE_SECS
+                );
+                self.cancel_scheduled_for(pid);
+                self.kill_escalation = KillEscalation::AwaitingGraceful {
+                    pid,
+                    name,
+                    sent_at: Instant::now(),
+                };
+            }
+        }
+        #[cfg(not(unix))]
+        {
+            // Windows에는 유예 시그널 개념이 없으므로 즉시 강제 종료한다
+            self.finish_kill(KillSignal::Force);
+            return;
+        }
+        self.mode = AppMode::ProcessSelect;
+        self.selected_pid = None;
+        self.timer_start = None;
+    }
+
+    /// 에스컬레이션 유예 시간이 지났는지 매 루프마다 점검한다
+    fn poll_escalation(&mut self) {
+        if let KillEscalation::AwaitingGraceful { pid, name, sent_at } = &self.kill_escalation {
+            if sent_at.elapsed() >= Duration::from_secs(ESCALATION_GRACE_SECS) {
+                let pid = *pid;
+                let name = name.clone();
+                self.system.refresh_process(pid);
+                if self.system.process(pid).is_some() {
+                    self.kill_pid(pid, KillSignal::Kill);
+                    self.status_message =
+                        format!("{}이(가) 응답하지 않아 SIGKILL로 종료했습니다.", name);
+                } else {
+                    self.status_message = format!("{}이(가) 정상적으로 종료되었습니다.", name);
+                }
+                self.kill_escalation = KillEscalation::None;
+                self.refresh_processes();
+            }
+        }
+    }
+
+    /// 현재 화면에서 확인 절차를 거치고 있는 항목을 제외한 모든 예약 종료를 점검하고,
+    /// 기한이 지난 항목은 즉시 실행한다 (백그라운드 예약이므로 별도 확인을 거치지 않는다)
+    fn poll_scheduled_kills(&mut self) {
+        let now = OffsetDateTime::now_utc();
+        let active_pid = if self.mode == AppMode::TimerRunning || self.mode == AppMode::KillConfirm
+        {
+            self.selected_pid
+        } else {
+            None
+        };
+
+        let mut due = Vec::new();
+        self.scheduled_kills.retain(|entry| {
+            if Some(entry.pid) == active_pid {
+                return true;
+            }
+            if entry.fire_at <= now {
+                due.push((entry.pid, entry.name.clone(), entry.signal));
+                false
+            } else {
+                true
+            }
+        });
+
+        if due.is_empty() {
+            return;
+        }
+
+        for (pid, name, signal) in due {
+            if self.kill_pid(pid, signal) {
+                self.status_message = format!("예약된 종료 실행: {}(이)가 종료되었습니다.", name);
+            } else {
+                self.status_message = format!("예약된 종료 실행 실패: {}", name);
+            }
+        }
+        self.refresh_processes();
+    }
+
+    fn kill_pid(&mut self, pid: Pid, signal: KillSignal) -> bool {
+        self.system.refresh_process(pid);
+        if let Some(process) = self.system.process(pid) {
+            #[cfg(windows)]
+            {
+                // Windows에서는 taskkill 명령어 사용
+                use std::process::Command;
+                let pid_u32: u32 = pid.into();
+                let pid_str = pid_u32.to_string();
+                let mut args = vec!["/PID".to_string(), pid_str];
+                if signal == KillSignal::Force {
+                    args.push("/F".to_string());
                 }
-                #[cfg(not(windows))]
-                {
-                    // Unix 계열에서는 kill 시그널 사용
-                    process.kill();
-                    return true;
+                let output = Command::new("taskkill").args(&args).output();
+
+                if let Ok(result) = output {
+                    return result.status.success();
                 }
+                return false;
+            }
+            #[cfg(not(windows))]
+            {
+                // Unix 계열에서는 선택된 시그널 사용
+                return process.kill_with(signal.to_sysinfo()).unwrap_or(false);
             }
         }
         false
     }
 }
 
+/// `--config <경로>`, `--basic`/`-b` 플래그만 인식하는 최소한의 명령행 파싱
+struct CliArgs {
+    config_path: Option<PathBuf>,
+    basic_mode: bool,
+}
+
+fn parse_cli_args() -> CliArgs {
+    let mut config_path = None;
+    let mut basic_mode = false;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--config" => config_path = args.next().map(PathBuf::from),
+            "--basic" | "-b" => basic_mode = true,
+            _ => {}
+        }
+    }
+    CliArgs {
+        config_path,
+        basic_mode,
+    }
+}
+
 fn main() -> io::Result<()> {
+    let cli_args = parse_cli_args();
+    let mut config = Config::load(cli_args.config_path);
+    if cli_args.basic_mode {
+        config.basic_mode = true;
+    }
+
     enable_raw_mode()?;
     let mut stdout = stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let mut app = App::new();
+    let mut app = App::new(&config);
     let mut should_quit = false;
 
     while !should_quit {
-        terminal.draw(|f| ui(f, &app))?;
-        
+        terminal.draw(|f| ui(f, &mut app))?;
+
+        // 에스컬레이션 유예 시간 점검
+        app.poll_escalation();
+
+        // 백그라운드 예약 종료 점검 (현재 확인 중인 항목은 제외)
+        app.poll_scheduled_kills();
+
         // 타이머 체크
         if app.mode == AppMode::TimerRunning {
+            app.sample_usage();
             if let Some(remaining) = app.get_remaining_time() {
                 if remaining == 0 {
-                    if app.kill_process() {
-                        app.status_message = format!("프로세스가 종료되었습니다.");
-                        app.mode = AppMode::ProcessSelect;
-                        app.selected_pid = None;
-                        app.timer_start = None;
-                        app.refresh_processes();
-                    } else {
-                        app.status_message = format!("프로세스 종료 실패");
-                        app.mode = AppMode::ProcessSelect;
-                    }
+                    app.request_kill();
                 }
             }
         }
 
         should_quit = handle_events(&mut app)?;
-        
+
         // 프로세스 목록 주기적 갱신
         if app.mode == AppMode::ProcessSelect {
             app.refresh_processes();
@@ -252,7 +1095,12 @@ fn main() -> io::Result<()> {
     Ok(())
 }
 
-fn ui(frame: &mut Frame, app: &App) {
+fn ui(frame: &mut Frame, app: &mut App) {
+    if app.basic_mode {
+        render_basic(frame, app);
+        return;
+    }
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -279,6 +1127,11 @@ fn ui(frame: &mut Frame, app: &App) {
         AppMode::ProcessSelect => render_process_list(frame, app, chunks[1]),
         AppMode::TimerInput => render_timer_input(frame, app, chunks[1]),
         AppMode::TimerRunning => render_timer_running(frame, app, chunks[1]),
+        AppMode::KillConfirm => {
+            render_timer_running(frame, app, chunks[1]);
+            render_kill_confirm(frame, app, frame.area());
+        }
+        AppMode::ScheduleList => render_schedule_list(frame, app, chunks[1]),
     }
 
     // 상태 메시지
@@ -290,9 +1143,17 @@ fn ui(frame: &mut Frame, app: &App) {
 
     // 도움말
     let help_text = match app.mode {
-        AppMode::ProcessSelect => "↑↓: 이동 | /: 검색 | Enter: 선택 | Q: 종료",
-        AppMode::TimerInput => "분:초 형식 입력 (예: 5:30) | Enter: 시작 | Esc: 취소",
-        AppMode::TimerRunning => "Q: 타이머 취소",
+        AppMode::ProcessSelect => {
+            "↑↓: 이동 | /: 검색 | s: 정렬 | S: 정렬 방향 | l: 예약 목록 | Enter: 선택 | F2: 간략 모드 | Q: 종료"
+        }
+        AppMode::TimerInput => {
+            "mm:ss, 초, HH:MM(절대시각), YYYY-MM-DD HH:MM 입력 | F1,F3~F9: 사전 설정 | Enter: 예약 | Esc: 취소"
+        }
+        AppMode::TimerRunning => "Q: 타이머 취소 | b: 백그라운드로 보내기 | F2: 간략 모드",
+        AppMode::KillConfirm => {
+            "←→: 시그널 선택 | e: 에스컬레이션 전환 | Enter: 종료 확인 | Esc: 취소"
+        }
+        AppMode::ScheduleList => "↑↓: 이동 | d: 취소 | Esc/Q: 돌아가기",
     };
     let help = Paragraph::new(help_text)
         .block(Block::default().borders(Borders::ALL).title("도움말"))
@@ -301,53 +1162,170 @@ fn ui(frame: &mut Frame, app: &App) {
     frame.render_widget(help, chunks[3]);
 }
 
-fn render_process_list(frame: &mut Frame, app: &App, area: Rect) {
+/// 작은 터미널을 위한 간략 모드: 헤더, 도움말/상태 영역과 그래프를 생략한다.
+/// 타이머 실행 중에는 프로세스 한 줄과 남은 시간만 보여주고, 다른 화면은
+/// 일반 모드와 같은 목록/입력 화면을 전체 영역에 그린다
+fn render_basic(frame: &mut Frame, app: &mut App) {
+    let area = frame.area();
+
+    match app.mode {
+        AppMode::TimerRunning | AppMode::KillConfirm => {
+            let remaining = app.get_remaining_time().unwrap_or(0);
+            let time_str = format!("{:02}:{:02}", remaining / 60, remaining % 60);
+            let line = Paragraph::new(format!(
+                "{} | 남은 시간 {} | F2: 일반 모드",
+                app.selected_process_name(),
+                time_str
+            ))
+            .style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+            .alignment(Alignment::Center);
+            frame.render_widget(line, area);
+
+            if app.mode == AppMode::KillConfirm {
+                render_kill_confirm(frame, app, area);
+            }
+        }
+        AppMode::ProcessSelect => render_process_list(frame, app, area),
+        AppMode::TimerInput => render_timer_input(frame, app, area),
+        AppMode::ScheduleList => render_schedule_list(frame, app, area),
+    }
+}
+
+/// 퍼지 매치된 글자를 굵게 강조한 Span 목록을 만든다
+fn highlight_matches(name: &str, match_indices: &[usize]) -> Vec<Span<'static>> {
+    name.chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if match_indices.contains(&i) {
+                Span::styled(
+                    c.to_string(),
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Span::raw(c.to_string())
+            }
+        })
+        .collect()
+}
+
+fn render_process_list(frame: &mut Frame, app: &mut App, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
         .split(area);
 
     // 프로세스 목록
-    let items: Vec<ListItem> = app
+    let sort_indicator = |mode: SortMode| {
+        if app.sort_mode == mode {
+            if app.sort_ascending {
+                " ▲"
+            } else {
+                " ▼"
+            }
+        } else {
+            ""
+        }
+    };
+
+    let header = Row::new(vec![
+        Cell::from(format!("PID{}", sort_indicator(SortMode::Pid))),
+        Cell::from(format!("이름{}", sort_indicator(SortMode::Name))),
+        Cell::from(format!("CPU%{}", sort_indicator(SortMode::Cpu))),
+        Cell::from(format!("메모리{}", sort_indicator(SortMode::Memory))),
+        Cell::from("PPID"),
+        Cell::from("시간"),
+    ])
+    .style(Style::default().add_modifier(Modifier::BOLD));
+
+    let rows: Vec<Row> = app
         .filtered_processes
         .iter()
-        .enumerate()
-        .map(|(i, (pid, name))| {
-            let is_selected = app.list_state.selected() == Some(i);
-            let style = if is_selected {
-                Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::White)
-                    .add_modifier(Modifier::BOLD)
-            } else {
-                Style::default().fg(Color::White)
-            };
-            
-            let pid_u32: u32 = (*pid).into();
-            let content = format!("[{}] {}", pid_u32, name);
-            ListItem::new(content).style(style)
+        .map(|p| {
+            let pid_u32: u32 = p.pid.into();
+            let ppid = p
+                .parent_pid
+                .map(|ppid| {
+                    let ppid_u32: u32 = ppid.into();
+                    ppid_u32.to_string()
+                })
+                .unwrap_or_else(|| "-".to_string());
+            let run_time = format!(
+                "{:02}:{:02}:{:02}",
+                p.run_time / 3600,
+                (p.run_time % 3600) / 60,
+                p.run_time % 60
+            );
+            Row::new(vec![
+                Cell::from(pid_u32.to_string()),
+                Cell::from(Line::from(highlight_matches(&p.name, &p.match_indices))),
+                Cell::from(format!("{:.1}", p.cpu_usage)),
+                Cell::from(format!("{} MB", p.memory / 1024 / 1024)),
+                Cell::from(ppid),
+                Cell::from(run_time),
+            ])
         })
         .collect();
 
-    let list = List::new(items)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title("프로세스 목록"),
-        )
-        .highlight_style(Style::default().add_modifier(Modifier::BOLD));
-    
-    frame.render_stateful_widget(list, chunks[0], &mut app.list_state.clone());
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("프로세스 목록");
+
+    // 마우스 클릭을 행 인덱스로 변환할 때 사용할 실제 내용 영역(헤더 제외)을 기억해 둔다
+    let mut content_area = block.inner(chunks[0]);
+    if content_area.height > 0 {
+        content_area.y += 1;
+        content_area.height -= 1;
+    }
+    app.process_list_area = content_area;
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(8),
+            Constraint::Min(20),
+            Constraint::Length(8),
+            Constraint::Length(10),
+            Constraint::Length(8),
+            Constraint::Length(10),
+        ],
+    )
+    .header(header)
+    .block(block)
+    .row_highlight_style(
+        Style::default()
+            .fg(Color::Black)
+            .bg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut table_state = TableState::default().with_selected(app.list_state.selected());
+    frame.render_stateful_widget(table, chunks[0], &mut table_state);
+    app.process_list_scroll_offset = table_state.offset();
 
     // 검색 영역
+    let search_title = if app.search_active {
+        "검색 (입력 중, Enter/Esc로 종료)"
+    } else {
+        "검색"
+    };
     let search_label = if app.search_query.is_empty() {
-        "검색어 입력 (/ 입력 후 검색)"
+        if app.search_active {
+            String::new()
+        } else {
+            String::from("검색어 입력 (/ 입력 후 검색)")
+        }
+    } else if app.search_active {
+        format!("{}▏", app.search_query)
     } else {
-        app.search_query.as_str()
+        app.search_query.clone()
     };
     let search = Paragraph::new(search_label)
-        .block(Block::default().borders(Borders::ALL).title("검색"))
-        .style(if app.search_query.is_empty() {
+        .block(Block::default().borders(Borders::ALL).title(search_title))
+        .style(if app.search_active {
+            Style::default().fg(Color::Yellow)
+        } else if app.search_query.is_empty() {
             Style::default().fg(Color::Gray)
         } else {
             Style::default().fg(Color::Green)
@@ -364,8 +1342,8 @@ fn render_timer_input(frame: &mut Frame, app: &App, area: Rect) {
     let selected_process = if let Some(pid) = app.selected_pid {
         app.processes
             .iter()
-            .find(|(p, _)| *p == pid)
-            .map(|(_, name)| name.clone())
+            .find(|p| p.pid == pid)
+            .map(|p| p.name.clone())
             .unwrap_or_else(|| "알 수 없음".to_string())
     } else {
         "없음".to_string()
@@ -384,7 +1362,7 @@ fn render_timer_input(frame: &mut Frame, app: &App, area: Rect) {
     ])
     .block(Block::default().borders(Borders::ALL).title("타이머 설정"))
     .wrap(Wrap { trim: true });
-    
+
     frame.render_widget(info, chunks[0]);
 }
 
@@ -397,8 +1375,8 @@ fn render_timer_running(frame: &mut Frame, app: &App, area: Rect) {
     let selected_process = if let Some(pid) = app.selected_pid {
         app.processes
             .iter()
-            .find(|(p, _)| *p == pid)
-            .map(|(_, name)| name.clone())
+            .find(|p| p.pid == pid)
+            .map(|p| p.name.clone())
             .unwrap_or_else(|| "알 수 없음".to_string())
     } else {
         "없음".to_string()
@@ -417,32 +1395,258 @@ fn render_timer_running(frame: &mut Frame, app: &App, area: Rect) {
         Line::from(""),
         Line::from(vec![Span::styled(
             format!("남은 시간: {}", time_str),
-            Style::default()
-                .fg(Color::Red)
-                .add_modifier(Modifier::BOLD),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
         )]),
     ])
-    .block(Block::default().borders(Borders::ALL).title("타이머 실행 중"))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("타이머 실행 중"),
+    )
     .wrap(Wrap { trim: true })
     .alignment(Alignment::Center);
-    
+
     frame.render_widget(info, chunks[0]);
+
+    render_usage_graph(frame, app, chunks[1]);
+}
+
+fn render_usage_graph(frame: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+
+    if app.usage_history.is_empty() {
+        let placeholder = Paragraph::new("사용량 데이터를 수집 중입니다...")
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("CPU / 메모리 사용량"),
+            )
+            .alignment(Alignment::Center);
+        frame.render_widget(placeholder, area);
+        return;
+    }
+
+    let x_max = app
+        .usage_history
+        .back()
+        .map(|s| s.elapsed_secs)
+        .unwrap_or(1.0)
+        .max(1.0);
+
+    let cpu_points: Vec<(f64, f64)> = app
+        .usage_history
+        .iter()
+        .map(|s| (s.elapsed_secs, s.cpu_usage))
+        .collect();
+    let cpu_max = cpu_points
+        .iter()
+        .map(|(_, v)| *v)
+        .fold(0.0_f64, f64::max)
+        .max(1.0);
+
+    let cpu_dataset = Dataset::default()
+        .name("CPU %")
+        .marker(ratatui::symbols::Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(Color::Green))
+        .data(&cpu_points);
+
+    let cpu_chart = Chart::new(vec![cpu_dataset])
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("CPU 사용률 (%)"),
+        )
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds([0.0, x_max]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds([0.0, cpu_max * 1.1])
+                .labels(vec!["0".to_string(), format!("{:.0}", cpu_max * 1.1)]),
+        );
+    frame.render_widget(cpu_chart, chunks[0]);
+
+    let mem_points: Vec<(f64, f64)> = app
+        .usage_history
+        .iter()
+        .map(|s| (s.elapsed_secs, (s.memory / 1024 / 1024) as f64))
+        .collect();
+    let mem_max = mem_points
+        .iter()
+        .map(|(_, v)| *v)
+        .fold(0.0_f64, f64::max)
+        .max(1.0);
+
+    let mem_dataset = Dataset::default()
+        .name("메모리 MB")
+        .marker(ratatui::symbols::Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(Color::Magenta))
+        .data(&mem_points);
+
+    let mem_chart = Chart::new(vec![mem_dataset])
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("메모리 사용량 (MB)"),
+        )
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds([0.0, x_max]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds([0.0, mem_max * 1.1])
+                .labels(vec!["0".to_string(), format!("{:.0}", mem_max * 1.1)]),
+        );
+    frame.render_widget(mem_chart, chunks[1]);
+}
+
+fn render_schedule_list(frame: &mut Frame, app: &App, area: Rect) {
+    let header = Row::new(vec![
+        Cell::from("PID"),
+        Cell::from("이름"),
+        Cell::from("남은 시간"),
+        Cell::from("시그널"),
+    ])
+    .style(Style::default().add_modifier(Modifier::BOLD));
+
+    let now = OffsetDateTime::now_utc();
+    let rows: Vec<Row> = app
+        .scheduled_kills
+        .iter()
+        .map(|entry| {
+            let pid_u32: u32 = entry.pid.into();
+            let remaining = (entry.fire_at - now).whole_seconds().max(0) as u64;
+            let remaining_str = format!(
+                "{:02}:{:02}:{:02}",
+                remaining / 3600,
+                (remaining % 3600) / 60,
+                remaining % 60
+            );
+            Row::new(vec![
+                Cell::from(pid_u32.to_string()),
+                Cell::from(entry.name.clone()),
+                Cell::from(remaining_str),
+                Cell::from(entry.signal.label()),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(8),
+            Constraint::Min(20),
+            Constraint::Length(10),
+            Constraint::Length(20),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("예약된 종료 ({}건)", app.scheduled_kills.len())),
+    )
+    .row_highlight_style(
+        Style::default()
+            .fg(Color::Black)
+            .bg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut table_state = TableState::default().with_selected(app.schedule_list_state.selected());
+    frame.render_stateful_widget(table, area, &mut table_state);
+}
+
+fn render_kill_confirm(frame: &mut Frame, app: &App, area: Rect) {
+    let popup_area = centered_rect(50, 30, area);
+
+    let selected_process = app.selected_process_name();
+
+    let lines = vec![
+        Line::from(vec![Span::styled(
+            format!("\"{}\" 프로세스를 종료합니다.", selected_process),
+            Style::default().fg(Color::Cyan),
+        )]),
+        Line::from(""),
+        Line::from(vec![Span::styled(
+            format!("시그널: {}", app.kill_signal.label()),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(vec![Span::styled(
+            format!(
+                "에스컬레이션: {}",
+                if app.escalation_enabled { "켜짐" } else { "꺼짐" }
+            ),
+            Style::default().fg(Color::Magenta),
+        )]),
+        Line::from(""),
+        Line::from("Enter: 확인 | ←→: 시그널 변경 | e: 에스컬레이션 전환 | Esc: 취소"),
+    ];
+
+    let dialog = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("종료 확인")
+                .title_alignment(Alignment::Center),
+        )
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(dialog, popup_area);
+}
+
+/// 주어진 영역 가운데에 퍼센트 크기의 팝업 영역을 계산한다
+fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(area);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(vertical[1])[1]
 }
 
 fn handle_events(app: &mut App) -> io::Result<bool> {
-    if event::poll(Duration::from_millis(100))? {
-        if let Event::Key(key) = event::read()? {
-            if key.kind == KeyEventKind::Press {
-                match app.mode {
-                    AppMode::ProcessSelect => {
-                        match key.code {
-                            KeyCode::Char('q') | KeyCode::Char('Q') => return Ok(true),
-                            KeyCode::Up => app.previous(),
-                            KeyCode::Down => app.next(),
-                            KeyCode::Char('/') => {
-                                app.search_query.clear();
+    if event::poll(app.refresh_interval)? {
+        match event::read()? {
+            Event::Key(key) => {
+                if key.kind == KeyEventKind::Press {
+                    if key.code == KeyCode::F(2) {
+                        app.basic_mode = !app.basic_mode;
+                        return Ok(false);
+                    }
+                    match app.mode {
+                        AppMode::ProcessSelect if app.search_active => match key.code {
+                            KeyCode::Esc => app.search_active = false,
+                            KeyCode::Enter => {
+                                app.search_active = false;
+                                app.select_process();
                             }
-                            KeyCode::Enter => app.select_process(),
                             KeyCode::Char(c) => {
                                 app.search_query.push(c);
                                 app.filter_processes();
@@ -452,10 +1656,26 @@ fn handle_events(app: &mut App) -> io::Result<bool> {
                                 app.filter_processes();
                             }
                             _ => {}
-                        }
-                    }
-                    AppMode::TimerInput => {
-                        match key.code {
+                        },
+                        AppMode::ProcessSelect => match key.code {
+                            KeyCode::Char('q') | KeyCode::Char('Q') => return Ok(true),
+                            KeyCode::Up => app.previous(),
+                            KeyCode::Down => app.next(),
+                            KeyCode::Char('/') => {
+                                app.search_active = true;
+                            }
+                            KeyCode::Char('s') => app.cycle_sort_mode(),
+                            KeyCode::Char('S') => app.toggle_sort_direction(),
+                            KeyCode::Char('l') | KeyCode::Char('L') => {
+                                app.mode = AppMode::ScheduleList;
+                                if !app.scheduled_kills.is_empty() {
+                                    app.schedule_list_state.select(Some(0));
+                                }
+                            }
+                            KeyCode::Enter => app.select_process(),
+                            _ => {}
+                        },
+                        AppMode::TimerInput => match key.code {
                             KeyCode::Char('q') | KeyCode::Char('Q') => return Ok(true),
                             KeyCode::Esc => {
                                 app.mode = AppMode::ProcessSelect;
@@ -464,6 +1684,7 @@ fn handle_events(app: &mut App) -> io::Result<bool> {
                                 app.status_message = String::from("프로세스를 선택하세요");
                             }
                             KeyCode::Enter => app.start_timer(),
+                            KeyCode::F(n) => app.fill_preset_timer(n),
                             KeyCode::Char(c) => {
                                 app.timer_input.push(c);
                             }
@@ -471,22 +1692,73 @@ fn handle_events(app: &mut App) -> io::Result<bool> {
                                 app.timer_input.pop();
                             }
                             _ => {}
-                        }
-                    }
-                    AppMode::TimerRunning => {
-                        match key.code {
+                        },
+                        AppMode::TimerRunning => match key.code {
                             KeyCode::Char('q') | KeyCode::Char('Q') => {
+                                if let Some(pid) = app.selected_pid {
+                                    app.cancel_scheduled_for(pid);
+                                }
                                 app.mode = AppMode::ProcessSelect;
                                 app.selected_pid = None;
                                 app.timer_start = None;
                                 app.status_message = String::from("타이머가 취소되었습니다");
                             }
+                            KeyCode::Char('b') | KeyCode::Char('B') => app.detach_timer(),
                             _ => {}
-                        }
+                        },
+                        AppMode::KillConfirm => match key.code {
+                            KeyCode::Enter => app.confirm_kill(),
+                            KeyCode::Esc => app.cancel_kill(),
+                            KeyCode::Left | KeyCode::Right => app.cycle_kill_signal(),
+                            KeyCode::Char('e') | KeyCode::Char('E') => app.toggle_escalation(),
+                            _ => {}
+                        },
+                        AppMode::ScheduleList => match key.code {
+                            KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => {
+                                app.mode = AppMode::ProcessSelect;
+                            }
+                            KeyCode::Up => app.schedule_list_previous(),
+                            KeyCode::Down => app.schedule_list_next(),
+                            KeyCode::Char('d') | KeyCode::Delete => app.cancel_selected_schedule(),
+                            _ => {}
+                        },
                     }
                 }
             }
+            Event::Mouse(mouse) => handle_mouse_event(app, mouse),
+            _ => {}
         }
     }
     Ok(false)
 }
+
+/// 더블 클릭으로 간주할 두 클릭 사이의 최대 간격
+const DOUBLE_CLICK_WINDOW: Duration = Duration::from_millis(400);
+
+fn handle_mouse_event(app: &mut App, mouse: MouseEvent) {
+    if app.mode != AppMode::ProcessSelect {
+        return;
+    }
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            if let Some(index) = app.process_list_index_at(mouse.column, mouse.row) {
+                let is_double_click = app
+                    .last_click
+                    .map(|(at, last_index)| {
+                        last_index == index && at.elapsed() <= DOUBLE_CLICK_WINDOW
+                    })
+                    .unwrap_or(false);
+                app.list_state.select(Some(index));
+                if is_double_click {
+                    app.last_click = None;
+                    app.select_process();
+                } else {
+                    app.last_click = Some((Instant::now(), index));
+                }
+            }
+        }
+        MouseEventKind::ScrollUp => app.previous(),
+        MouseEventKind::ScrollDown => app.next(),
+        _ => {}
+    }
+}